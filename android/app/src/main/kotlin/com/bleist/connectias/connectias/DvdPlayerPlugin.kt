@@ -14,7 +14,7 @@ import kotlin.concurrent.thread
 
 /**
  * DVD Player plugin using LibVLC with custom I/O via pipe.
- * Streams data from Rust/NativeBridge.dvdReadStream into a pipe; LibVLC reads from the pipe FD.
+ * Streams data from Rust/NativeBridge.dvdReadStreamDirect into a pipe; LibVLC reads from the pipe FD.
  * MethodChannel: com.bleist.connectias/dvd
  */
 class DvdPlayerPlugin(private val activity: Activity) : MethodChannel.MethodCallHandler {
@@ -87,15 +87,16 @@ class DvdPlayerPlugin(private val activity: Activity) : MethodChannel.MethodCall
 
         readerRunning.set(true)
         readerThread = thread(name = "dvd-stream-reader") {
-            val buf = ByteArray(256 * 1024)
+            val buf = java.nio.ByteBuffer.allocateDirect(256 * 1024)
             try {
                 writeFd?.fileDescriptor?.let { fd ->
                     java.io.FileOutputStream(fd).use { out ->
+                        val channel = out.channel
                         while (readerRunning.get() && streamId >= 0) {
-                            val n = NativeBridge.dvdReadStream(streamId, buf)
+                            val n = NativeBridge.dvdReadStreamDirect(streamId, buf)
                             if (n <= 0) break
-                            out.write(buf, 0, n)
-                            out.flush()
+                            buf.clear().limit(n)
+                            while (buf.hasRemaining()) channel.write(buf)
                         }
                     }
                 }
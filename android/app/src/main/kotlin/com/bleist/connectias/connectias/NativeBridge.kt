@@ -52,6 +52,13 @@ object NativeBridge {
     external fun dvdListChapters(dvdHandle: Long, titleId: Int): String?
     external fun dvdOpenTitleStream(dvdHandle: Long, titleId: Int): Long
     external fun dvdReadStream(streamId: Long, buffer: ByteArray): Int
+
+    /**
+     * Reads stream data straight into a direct ByteBuffer (no JNI array copy).
+     * Fills from position 0 up to the buffer's capacity; position and limit are not changed.
+     * @return bytes read, 0 at end of stream, -1 on error
+     */
+    external fun dvdReadStreamDirect(streamId: Long, buffer: java.nio.ByteBuffer): Int
     external fun dvdSeekStream(streamId: Long, offset: Long): Boolean
     external fun dvdCloseStream(streamId: Long)
 }
@@ -1,9 +1,27 @@
 //! JNI bridge for Android: Rust calls back to Kotlin for bulk transfers.
 
-use jni::objects::{GlobalRef, JByteArray, JObject, JValue};
+use jni::objects::{GlobalRef, JByteArray, JObject, JString, JValue};
 use jni::JNIEnv;
 use std::io;
 
+/// Reads a Java string argument into an owned Rust string (lossy UTF-8).
+pub fn jstring_to_string(env: &mut JNIEnv<'_>, s: &JString<'_>) -> Option<String> {
+    env.get_string(s)
+        .ok()
+        .map(|s| s.to_string_lossy().into_owned())
+}
+
+/// Views a byte slice as JNI `jbyte`s so array regions can be copied without a temp buffer.
+#[cfg(has_dvd)]
+pub fn as_jbytes(buf: &[u8]) -> &[i8] {
+    unsafe { std::slice::from_raw_parts(buf.as_ptr() as *const i8, buf.len()) }
+}
+
+/// Mutable counterpart of [`as_jbytes`].
+pub fn as_jbytes_mut(buf: &mut [u8]) -> &mut [i8] {
+    unsafe { std::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut i8, buf.len()) }
+}
+
 /// Transfer handler that calls Kotlin BulkTransferHandler via JNI.
 pub struct JniTransferHandler {
     vm: jni::JavaVM,
@@ -59,15 +77,11 @@ impl crate::block_device::TransferHandler for JniTransferHandler {
                 &[JValue::Int(buf.len() as i32)],
             )?;
             let result_arr = result.l()?;
-            let byte_arr = unsafe { JByteArray::from_raw(result_arr.as_raw() as jni::sys::jarray) };
+            let byte_arr = JByteArray::from(result_arr);
             let len = env.get_array_length(&byte_arr)?;
             let copy_len = (len as usize).min(buf.len());
             if copy_len > 0 {
-                let mut temp = vec![0i8; copy_len];
-                env.get_byte_array_region(&byte_arr, 0, &mut temp)?;
-                for i in 0..copy_len {
-                    buf[i] = temp[i] as u8;
-                }
+                env.get_byte_array_region(&byte_arr, 0, as_jbytes_mut(&mut buf[..copy_len]))?;
             }
             Ok(copy_len)
         })
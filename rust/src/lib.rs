@@ -12,7 +12,8 @@ mod dvd;
 
 use block_device::ScsiBlockDevice;
 use jni_bridge::JniTransferHandler;
-use jni::objects::{JObject, JString};
+use jni::objects::{JClass, JObject, JString};
+use jni::JNIEnv;
 use ntfs_volume::{DirEntry, NtfsVolume};
 use std::collections::HashMap;
 use std::ffi::CString;
@@ -45,16 +46,12 @@ static VOLUME_ID: std::sync::LazyLock<Mutex<u64>> =
 // --- JNI entry points ---
 
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_openVolume(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_openVolume<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
     session_id: jni::sys::jlong,
-    handler: jni::sys::jobject,
+    handler: JObject<'local>,
 ) -> jni::sys::jlong {
-    let mut env = unsafe {
-        jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw")
-    };
-    let handler = unsafe { JObject::from_raw(handler) };
     let transfer = match JniTransferHandler::new(&mut env, handler) {
         Ok(t) => t,
         Err(e) => {
@@ -91,9 +88,9 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_openVo
 }
 
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_closeVolume(
-    _env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_closeVolume<'local>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
     volume_id: jni::sys::jlong,
 ) -> jni::sys::jint {
     let removed = VOLUMES.lock().unwrap().remove(&(volume_id as u64));
@@ -106,19 +103,15 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_closeV
 }
 
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_listDirectory(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_listDirectory<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
     volume_id: jni::sys::jlong,
-    path: jni::sys::jstring,
+    path: JString<'local>,
 ) -> jni::sys::jstring {
-    let mut env = unsafe {
-        jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw")
-    };
-    let path_jstr = unsafe { JString::from_raw(path) };
-    let path_str = match env.get_string(&path_jstr) {
-        Ok(s) => s.to_string_lossy().into_owned(),
-        Err(_) => {
+    let path_str = match jni_bridge::jstring_to_string(&mut env, &path) {
+        Some(s) => s,
+        None => {
             set_last_error("Invalid path");
             return std::ptr::null_mut();
         }
@@ -146,21 +139,17 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_listDi
 }
 
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_readFile(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_readFile<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
     volume_id: jni::sys::jlong,
-    path: jni::sys::jstring,
+    path: JString<'local>,
     offset: jni::sys::jlong,
     length: jni::sys::jint,
 ) -> jni::sys::jbyteArray {
-    let mut env = unsafe {
-        jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw")
-    };
-    let path_jstr = unsafe { JString::from_raw(path) };
-    let path_str = match env.get_string(&path_jstr) {
-        Ok(s) => s.to_string_lossy().into_owned(),
-        Err(_) => {
+    let path_str = match jni_bridge::jstring_to_string(&mut env, &path) {
+        Some(s) => s,
+        None => {
             set_last_error("Invalid path");
             return std::ptr::null_mut();
         }
@@ -187,14 +176,12 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_readFi
 }
 
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_getDeviceType(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_getDeviceType<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
     session_id: jni::sys::jlong,
-    handler: jni::sys::jobject,
+    handler: JObject<'local>,
 ) -> jni::sys::jstring {
-    let mut env = unsafe { jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw") };
-    let handler = unsafe { JObject::from_raw(handler) };
     let transfer = match JniTransferHandler::new(&mut env, handler) {
         Ok(t) => t,
         Err(e) => {
@@ -214,13 +201,10 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_getDev
 }
 
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_lastError(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_lastError<'local>(
+    env: JNIEnv<'local>,
+    _class: JClass<'local>,
 ) -> jni::sys::jstring {
-    let env = unsafe {
-        jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw")
-    };
     let msg = if let Ok(guard) = LAST_ERROR.lock() {
         guard.as_ref().map(|c| c.to_string_lossy().into_owned())
     } else {
@@ -258,14 +242,12 @@ fn escape_json(s: &str) -> String {
 
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_openDvd(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_openDvd<'local>(
+    mut env: JNIEnv<'local>,
+    _class: JClass<'local>,
     session_id: jni::sys::jlong,
-    handler: jni::sys::jobject,
+    handler: JObject<'local>,
 ) -> jni::sys::jlong {
-    let mut env = unsafe { jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw") };
-    let handler = unsafe { JObject::from_raw(handler) };
     let transfer = match JniTransferHandler::new(&mut env, handler) {
         Ok(t) => t,
         Err(e) => {
@@ -284,9 +266,9 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_openDv
 
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_closeDvd(
-    _env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_closeDvd<'local>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
     dvd_handle: jni::sys::jlong,
 ) {
     dvd::close_dvd(dvd_handle as u64);
@@ -294,12 +276,11 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_closeD
 
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdListTitles(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdListTitles<'local>(
+    env: JNIEnv<'local>,
+    _class: JClass<'local>,
     dvd_handle: jni::sys::jlong,
 ) -> jni::sys::jstring {
-    let mut env = unsafe { jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw") };
     match dvd::list_titles_json(dvd_handle as u64) {
         Ok(json) => env.new_string(&json).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut()),
         Err(e) => {
@@ -311,13 +292,12 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdLis
 
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdListChapters(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdListChapters<'local>(
+    env: JNIEnv<'local>,
+    _class: JClass<'local>,
     dvd_handle: jni::sys::jlong,
     title_id: jni::sys::jint,
 ) -> jni::sys::jstring {
-    let mut env = unsafe { jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw") };
     match dvd::list_chapters_json(dvd_handle as u64, title_id) {
         Ok(json) => env.new_string(&json).map(|s| s.into_raw()).unwrap_or(std::ptr::null_mut()),
         Err(e) => {
@@ -329,9 +309,9 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdLis
 
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdOpenTitleStream(
-    _env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdOpenTitleStream<'local>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
     dvd_handle: jni::sys::jlong,
     title_id: jni::sys::jint,
 ) -> jni::sys::jlong {
@@ -346,23 +326,20 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdOpe
 
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdReadStream(
-    env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdReadStream<'local>(
+    env: JNIEnv<'local>,
+    _class: JClass<'local>,
     stream_id: jni::sys::jlong,
-    buffer: jni::sys::jbyteArray,
+    buffer: jni::objects::JByteArray<'local>,
 ) -> jni::sys::jint {
-    let mut env = unsafe { jni::JNIEnv::from_raw(env).expect("JNIEnv from_raw") };
-    let buffer_obj = unsafe { jni::objects::JByteArray::from_raw(buffer) };
-    let len = env.get_array_length(&buffer_obj).unwrap_or(0) as usize;
+    let len = env.get_array_length(&buffer).unwrap_or(0) as usize;
     if len == 0 {
         return 0;
     }
     let mut buf = vec![0u8; len];
     match dvd::read_stream(stream_id as u64, &mut buf) {
         Ok(n) => {
-            let i8_buf: Vec<i8> = buf[..n].iter().map(|&b| b as i8).collect();
-            let _ = env.set_byte_array_region(&buffer_obj, 0, &i8_buf);
+            let _ = env.set_byte_array_region(&buffer, 0, jni_bridge::as_jbytes(&buf[..n]));
             n as i32
         }
         Err(e) => {
@@ -372,11 +349,43 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdRea
     }
 }
 
+/// Reads into a direct ByteBuffer without copying through a Java byte array.
+#[cfg(has_dvd)]
+#[no_mangle]
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdReadStreamDirect<'local>(
+    env: JNIEnv<'local>,
+    _class: JClass<'local>,
+    stream_id: jni::sys::jlong,
+    buffer: jni::objects::JByteBuffer<'local>,
+) -> jni::sys::jint {
+    let (ptr, len) = match (
+        env.get_direct_buffer_address(&buffer),
+        env.get_direct_buffer_capacity(&buffer),
+    ) {
+        (Ok(ptr), Ok(len)) => (ptr, len),
+        _ => {
+            set_last_error("Not a direct buffer");
+            return -1;
+        }
+    };
+    if len == 0 {
+        return 0;
+    }
+    let buf = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+    match dvd::read_stream(stream_id as u64, buf) {
+        Ok(n) => n as i32,
+        Err(e) => {
+            set_last_error(&e);
+            -1
+        }
+    }
+}
+
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdSeekStream(
-    _env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdSeekStream<'local>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
     stream_id: jni::sys::jlong,
     offset: jni::sys::jlong,
 ) -> jni::sys::jboolean {
@@ -391,9 +400,9 @@ pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdSee
 
 #[cfg(has_dvd)]
 #[no_mangle]
-pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdCloseStream(
-    _env: *mut jni::sys::JNIEnv,
-    _class: jni::sys::jclass,
+pub extern "system" fn Java_com_bleist_connectias_connectias_NativeBridge_dvdCloseStream<'local>(
+    _env: JNIEnv<'local>,
+    _class: JClass<'local>,
     stream_id: jni::sys::jlong,
 ) {
     dvd::close_stream(stream_id as u64);